@@ -3,21 +3,52 @@ use std::{
     hash::{BuildHasher, Hash},
 };
 
+use crate::entry::Entry;
+
 #[derive(Debug, Clone)]
 pub struct BiSetDataMap<K, V, S = RandomState, D = ()> {
-    left: HashMap<K, HashMap<V, D, S>, S>,
-    right: HashMap<V, HashMap<K, D, S>, S>,
+    pub(crate) left: HashMap<K, HashMap<V, D, S>, S>,
+    pub(crate) right: HashMap<V, HashMap<K, D, S>, S>,
+}
+
+impl<K, V, D> Default for BiSetDataMap<K, V, RandomState, D> {
+    #[inline]
+    fn default() -> BiSetDataMap<K, V, RandomState, D> {
+        BiSetDataMap {
+            left: HashMap::with_hasher(RandomState::default()),
+            right: HashMap::with_hasher(RandomState::default()),
+        }
+    }
 }
 
-impl<K, V, S, D> Default for BiSetDataMap<K, V, S, D>
+impl<K, V, S, D> BiSetDataMap<K, V, S, D>
 where
-    S: Default,
+    S: BuildHasher + Default,
 {
-    #[inline]
-    fn default() -> BiSetDataMap<K, V, S, D> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        BiSetDataMap {
+            left: HashMap::with_capacity_and_hasher(capacity, Default::default()),
+            right: HashMap::with_capacity_and_hasher(capacity, Default::default()),
+        }
+    }
+
+    pub fn with_hasher(hasher: S) -> Self
+    where
+        S: Clone,
+    {
+        BiSetDataMap {
+            left: HashMap::with_hasher(hasher.clone()),
+            right: HashMap::with_hasher(hasher),
+        }
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self
+    where
+        S: Clone,
+    {
         BiSetDataMap {
-            left: HashMap::with_hasher(Default::default()),
-            right: HashMap::with_hasher(Default::default()),
+            left: HashMap::with_capacity_and_hasher(capacity, hasher.clone()),
+            right: HashMap::with_capacity_and_hasher(capacity, hasher),
         }
     }
 }
@@ -27,7 +58,7 @@ where
     K: Eq + Hash + Clone,
     V: Eq + Hash + Clone,
     S: BuildHasher + Default,
-    D: Clone + Eq,
+    D: Clone,
 {
     pub fn get_left(&self, k: &K) -> Option<&HashMap<V, D, S>> {
         self.left.get(k)
@@ -45,29 +76,186 @@ where
         self.right.entry(v).or_default().insert(k, d);
     }
 
-    pub fn remove_left(&mut self, k: &K) {
-        Self::remove(&mut self.left, &mut self.right, k);
+    pub fn entry(&mut self, k: K, v: V) -> Entry<'_, K, V, D, S> {
+        Entry::new(self, k, v)
+    }
+
+    pub fn collect(&self) -> Vec<(K, Vec<V>)> {
+        self.left
+            .iter()
+            .map(|(k, vs)| (k.clone(), vs.keys().cloned().collect()))
+            .collect()
+    }
+
+    pub fn rev_collect(&self) -> Vec<(V, Vec<K>)> {
+        self.right
+            .iter()
+            .map(|(v, ks)| (v.clone(), ks.keys().cloned().collect()))
+            .collect()
+    }
+
+    pub fn flat_collect(&self) -> Vec<(K, V, D)> {
+        self.left
+            .iter()
+            .flat_map(|(k, vs)| vs.iter().map(move |(v, d)| (k.clone(), v.clone(), d.clone())))
+            .collect()
+    }
+
+    pub fn rev_flat_collect(&self) -> Vec<(V, K, D)> {
+        self.right
+            .iter()
+            .flat_map(|(v, ks)| ks.iter().map(move |(k, d)| (v.clone(), k.clone(), d.clone())))
+            .collect()
+    }
+
+    pub fn contains(&self, k: &K, v: &V) -> bool {
+        self.left.get(k).is_some_and(|vs| vs.contains_key(v))
+    }
+
+    pub fn key_exists(&self, k: &K) -> bool {
+        self.left.contains_key(k)
+    }
+
+    pub fn value_exists(&self, v: &V) -> bool {
+        self.right.contains_key(v)
+    }
+
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.left.capacity().min(self.right.capacity())
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.left.reserve(additional);
+        self.right.reserve(additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.left.shrink_to_fit();
+        self.right.shrink_to_fit();
+        for vs in self.left.values_mut() {
+            vs.shrink_to_fit();
+        }
+        for ks in self.right.values_mut() {
+            ks.shrink_to_fit();
+        }
+    }
+
+    pub fn remove_left(&mut self, k: &K) -> Option<HashMap<V, D, S>> {
+        Self::remove(&mut self.left, &mut self.right, k)
     }
 
-    pub fn remove_right(&mut self, v: &V) {
-        Self::remove(&mut self.right, &mut self.left, v);
+    pub fn remove_right(&mut self, v: &V) -> Option<HashMap<K, D, S>> {
+        Self::remove(&mut self.right, &mut self.left, v)
+    }
+
+    pub fn remove_pair(&mut self, k: &K, v: &V) -> Option<D> {
+        let d = self.left.get_mut(k).and_then(|vs| vs.remove(v))?;
+
+        if self.left.get(k).is_some_and(HashMap::is_empty) {
+            self.left.remove(k);
+        }
+
+        let ks = self.right.get_mut(v).unwrap();
+        ks.remove(k);
+        if ks.is_empty() {
+            self.right.remove(v);
+        }
+
+        Some(d)
     }
 
     fn remove<A: Eq + Hash + Clone, B: Eq + Hash + Clone>(
         left_map: &mut HashMap<A, HashMap<B, D, S>, S>,
         right_map: &mut HashMap<B, HashMap<A, D, S>, S>,
         k: &A,
-    ) {
-        let left = left_map.remove(k);
-        if let Some(left) = left {
-            for right in left {
-                let elem = right_map.get_mut(&right.0).unwrap();
-                elem.remove(k);
-                if elem.is_empty() {
-                    right_map.remove(&right.0);
-                }
+    ) -> Option<HashMap<B, D, S>> {
+        let left = left_map.remove(k)?;
+        for right in left.keys() {
+            let elem = right_map.get_mut(right).unwrap();
+            elem.remove(k);
+            if elem.is_empty() {
+                right_map.remove(right);
             }
         }
+        Some(left)
+    }
+}
+
+impl<K, V, S, D> BiSetDataMap<K, V, S, D>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    pub fn iter(&self) -> std::vec::IntoIter<(K, V, D)> {
+        self.flat_collect().into_iter()
+    }
+}
+
+impl<K, V, S, D> FromIterator<(K, V, D)> for BiSetDataMap<K, V, S, D>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default + Clone,
+    D: Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V, D)>>(iter: I) -> Self {
+        let mut map = Self::with_hasher(S::default());
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S, D> Extend<(K, V, D)> for BiSetDataMap<K, V, S, D>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    fn extend<I: IntoIterator<Item = (K, V, D)>>(&mut self, iter: I) {
+        for (k, v, d) in iter {
+            self.insert(k, v, d);
+        }
+    }
+}
+
+impl<K, V, S, D> IntoIterator for BiSetDataMap<K, V, S, D>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    type Item = (K, V, D);
+    type IntoIter = std::vec::IntoIter<(K, V, D)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.flat_collect().into_iter()
+    }
+}
+
+impl<K, V, S, D> IntoIterator for &BiSetDataMap<K, V, S, D>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    type Item = (K, V, D);
+    type IntoIter = std::vec::IntoIter<(K, V, D)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
@@ -92,6 +280,170 @@ mod tests {
             &HashMap::from([(20, "test3"), (21, "test4")])
         );
     }
+
+    #[test]
+    fn collect_and_flat_collect() {
+        let mut bivecmap = BiSetDataMap::default();
+        bivecmap.insert(1, 10, "test1");
+        bivecmap.insert(1, 11, "test2");
+
+        let mut collected = bivecmap.collect();
+        collected.iter_mut().for_each(|(_, vs)| vs.sort());
+        assert_eq!(collected, vec![(1, vec![10, 11])]);
+
+        let mut flat = bivecmap.flat_collect();
+        flat.sort();
+        assert_eq!(flat, vec![(1, 10, "test1"), (1, 11, "test2")]);
+
+        let mut rev_flat = bivecmap.rev_flat_collect();
+        rev_flat.sort();
+        assert_eq!(rev_flat, vec![(10, 1, "test1"), (11, 1, "test2")]);
+    }
+
+    #[test]
+    fn contains_and_len() {
+        let mut bivecmap = BiSetDataMap::default();
+        assert!(bivecmap.is_empty());
+
+        bivecmap.insert(1, 10, "test1");
+
+        assert!(bivecmap.contains(&1, &10));
+        assert!(!bivecmap.contains(&1, &11));
+        assert!(bivecmap.key_exists(&1));
+        assert!(bivecmap.value_exists(&10));
+        assert!(!bivecmap.value_exists(&11));
+        assert_eq!(bivecmap.len(), 1);
+        assert!(!bivecmap.is_empty());
+    }
+
+    #[test]
+    fn remove_pair_unlinks_single_association() {
+        let mut bivecmap = BiSetDataMap::default();
+        bivecmap.insert(1, 10, "test1");
+        bivecmap.insert(1, 11, "test2");
+
+        assert_eq!(bivecmap.remove_pair(&1, &10), Some("test1"));
+        assert_eq!(bivecmap.remove_pair(&1, &10), None);
+
+        assert_eq!(bivecmap.get_left(&1).unwrap(), &HashMap::from([(11, "test2")]));
+        assert_eq!(bivecmap.get_right(&10), None);
+    }
+
+    #[test]
+    fn remove_pair_prunes_empty_key() {
+        let mut bivecmap = BiSetDataMap::default();
+        bivecmap.insert(1, 10, "test1");
+
+        assert_eq!(bivecmap.remove_pair(&1, &10), Some("test1"));
+        assert_eq!(bivecmap.get_left(&1), None);
+        assert_eq!(bivecmap.get_right(&10), None);
+    }
+
+    #[test]
+    fn remove_left_returns_removed_counterparts() {
+        let mut bivecmap = BiSetDataMap::default();
+        bivecmap.insert(1, 10, "test1");
+        bivecmap.insert(1, 11, "test2");
+
+        assert_eq!(
+            bivecmap.remove_left(&1),
+            Some(HashMap::from([(10, "test1"), (11, "test2")]))
+        );
+        assert_eq!(bivecmap.remove_left(&1), None);
+    }
+
+    #[test]
+    fn entry_or_insert_accumulates() {
+        let mut bivecmap: BiSetDataMap<_, _, _, i32> = BiSetDataMap::default();
+
+        *bivecmap.entry(1, 10).or_insert(0) += 1;
+        *bivecmap.entry(1, 10).or_insert(0) += 1;
+
+        assert_eq!(bivecmap.get_left(&1).unwrap(), &HashMap::from([(10, 2)]));
+        assert_eq!(bivecmap.get_right(&10).unwrap(), &HashMap::from([(1, 2)]));
+    }
+
+    #[test]
+    fn non_eq_data_accumulates_as_edge_weight() {
+        let mut bivecmap: BiSetDataMap<_, _, _, f64> = BiSetDataMap::default();
+
+        *bivecmap.entry(1, 10).or_insert(0.0) += 1.5;
+        *bivecmap.entry(1, 10).or_insert(0.0) += 2.5;
+
+        assert_eq!(bivecmap.get_left(&1).unwrap(), &HashMap::from([(10, 4.0)]));
+        assert_eq!(bivecmap.get_right(&10).unwrap(), &HashMap::from([(1, 4.0)]));
+    }
+
+    #[test]
+    fn entry_and_modify_keeps_both_sides_in_sync() {
+        let mut bivecmap = BiSetDataMap::default();
+        bivecmap.insert(1, 10, "test1");
+
+        bivecmap.entry(1, 10).and_modify(|d| *d = "updated");
+
+        assert_eq!(bivecmap.get_left(&1).unwrap(), &HashMap::from([(10, "updated")]));
+        assert_eq!(bivecmap.get_right(&10).unwrap(), &HashMap::from([(1, "updated")]));
+    }
+
+    #[test]
+    fn entry_remove_unlinks_pair() {
+        let mut bivecmap = BiSetDataMap::default();
+        bivecmap.insert(1, 10, "test1");
+
+        assert_eq!(bivecmap.entry(1, 10).remove(), Some("test1"));
+        assert_eq!(bivecmap.get_left(&1), None);
+        assert_eq!(bivecmap.get_right(&10), None);
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut bivecmap: BiSetDataMap<_, _, RandomState, _> = BiSetDataMap::with_capacity(4);
+        bivecmap.insert(1, 10, "test1");
+
+        assert_eq!(bivecmap.get_left(&1).unwrap(), &HashMap::from([(10, "test1")]));
+        assert!(bivecmap.capacity() >= 4);
+    }
+
+    #[test]
+    fn reserve_and_shrink_to_fit() {
+        let mut bivecmap = BiSetDataMap::default();
+        bivecmap.reserve(8);
+        assert!(bivecmap.capacity() >= 8);
+
+        bivecmap.insert(1, 10, "test1");
+        bivecmap.remove_left(&1);
+        bivecmap.shrink_to_fit();
+
+        assert_eq!(bivecmap.get_left(&1), None);
+    }
+
+    #[test]
+    fn from_iterator_and_extend() {
+        let mut bivecmap: BiSetDataMap<_, _, RandomState, _> =
+            [(1, 10, "test1"), (1, 11, "test2")].into_iter().collect();
+        bivecmap.extend([(2, 20, "test3")]);
+
+        assert_eq!(
+            bivecmap.get_left(&1).unwrap(),
+            &HashMap::from([(10, "test1"), (11, "test2")])
+        );
+        assert_eq!(bivecmap.get_left(&2).unwrap(), &HashMap::from([(20, "test3")]));
+    }
+
+    #[test]
+    fn into_iter_yields_flattened_associations() {
+        let mut bivecmap = BiSetDataMap::default();
+        bivecmap.insert(1, 10, "test1");
+        bivecmap.insert(1, 11, "test2");
+
+        let mut by_ref: Vec<_> = (&bivecmap).into_iter().collect();
+        by_ref.sort();
+        assert_eq!(by_ref, vec![(1, 10, "test1"), (1, 11, "test2")]);
+
+        let mut owned: Vec<_> = bivecmap.into_iter().collect();
+        owned.sort();
+        assert_eq!(owned, vec![(1, 10, "test1"), (1, 11, "test2")]);
+    }
     /*
     #[test]
     fn right_side() {
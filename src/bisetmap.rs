@@ -7,15 +7,33 @@ use crate::BiSetDataMap;
 
 pub struct BiSetMap<K, V, S = RandomState>(BiSetDataMap<K, V, S, ()>);
 
-impl<K, V, S> Default for BiSetMap<K, V, S>
+impl<K, V> Default for BiSetMap<K, V, RandomState> {
+    #[inline]
+    fn default() -> BiSetMap<K, V, RandomState> {
+        BiSetMap(BiSetDataMap::default())
+    }
+}
+
+impl<K, V, S> BiSetMap<K, V, S>
 where
-    S: Default,
+    S: BuildHasher + Default,
 {
-    #[inline]
-    fn default() -> BiSetMap<K, V, S> {
-        BiSetMap {
-            0: BiSetDataMap::default(),
-        }
+    pub fn with_capacity(capacity: usize) -> Self {
+        BiSetMap(BiSetDataMap::with_capacity(capacity))
+    }
+
+    pub fn with_hasher(hasher: S) -> Self
+    where
+        S: Clone,
+    {
+        BiSetMap(BiSetDataMap::with_hasher(hasher))
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self
+    where
+        S: Clone,
+    {
+        BiSetMap(BiSetDataMap::with_capacity_and_hasher(capacity, hasher))
     }
 }
 
@@ -37,13 +55,131 @@ where
         self.0.insert(k, v, ())
     }
 
-    pub fn remove_left(&mut self, k: &K) {
+    pub fn collect(&self) -> Vec<(K, Vec<V>)> {
+        self.0.collect()
+    }
+
+    pub fn rev_collect(&self) -> Vec<(V, Vec<K>)> {
+        self.0.rev_collect()
+    }
+
+    pub fn flat_collect(&self) -> Vec<(K, V)> {
+        self.0
+            .flat_collect()
+            .into_iter()
+            .map(|(k, v, _)| (k, v))
+            .collect()
+    }
+
+    pub fn rev_flat_collect(&self) -> Vec<(V, K)> {
+        self.0
+            .rev_flat_collect()
+            .into_iter()
+            .map(|(v, k, _)| (v, k))
+            .collect()
+    }
+
+    pub fn contains(&self, k: &K, v: &V) -> bool {
+        self.0.contains(k, v)
+    }
+
+    pub fn key_exists(&self, k: &K) -> bool {
+        self.0.key_exists(k)
+    }
+
+    pub fn value_exists(&self, v: &V) -> bool {
+        self.0.value_exists(v)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional)
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    pub fn remove_left(&mut self, k: &K) -> Option<HashMap<V, (), S>> {
         self.0.remove_left(k)
     }
 
-    pub fn remove_right(&mut self, v: &V) {
+    pub fn remove_right(&mut self, v: &V) -> Option<HashMap<K, (), S>> {
         self.0.remove_right(v)
     }
+
+    pub fn remove_pair(&mut self, k: &K, v: &V) -> Option<()> {
+        self.0.remove_pair(k, v)
+    }
+
+    pub fn iter(&self) -> std::vec::IntoIter<(K, V)> {
+        self.flat_collect().into_iter()
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for BiSetMap<K, V, S>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::with_hasher(S::default());
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for BiSetMap<K, V, S>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K, V, S> IntoIterator for BiSetMap<K, V, S>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.flat_collect().into_iter()
+    }
+}
+
+impl<K, V, S> IntoIterator for &BiSetMap<K, V, S>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 #[cfg(test)]
@@ -168,10 +304,109 @@ mod tests {
 
     #[test]
     fn with_hasher() {
-        let mut bivecmap = BiSetMap::<_, _, RandomState>::default();
+        let mut bivecmap = BiSetMap::with_hasher(RandomState::new());
         bivecmap.insert(1, 10);
 
         assert_eq!(bivecmap.get_left(&1).unwrap(), &HashMap::from([(10, ())]));
         assert_eq!(bivecmap.get_right(&10).unwrap(), &HashMap::from([(1, ())]));
     }
+
+    #[test]
+    fn collect_and_flat_collect() {
+        let mut bivecmap = BiSetMap::default();
+        bivecmap.insert(1, 10);
+        bivecmap.insert(1, 11);
+
+        let mut collected = bivecmap.collect();
+        collected.iter_mut().for_each(|(_, vs)| vs.sort());
+        assert_eq!(collected, vec![(1, vec![10, 11])]);
+
+        let mut flat = bivecmap.flat_collect();
+        flat.sort();
+        assert_eq!(flat, vec![(1, 10), (1, 11)]);
+    }
+
+    #[test]
+    fn contains_and_len() {
+        let mut bivecmap = BiSetMap::default();
+        assert!(bivecmap.is_empty());
+
+        bivecmap.insert(1, 10);
+
+        assert!(bivecmap.contains(&1, &10));
+        assert!(!bivecmap.contains(&1, &11));
+        assert!(bivecmap.key_exists(&1));
+        assert!(bivecmap.value_exists(&10));
+        assert_eq!(bivecmap.len(), 1);
+        assert!(!bivecmap.is_empty());
+    }
+
+    #[test]
+    fn remove_pair_unlinks_single_association() {
+        let mut bivecmap = BiSetMap::default();
+        bivecmap.insert(1, 10);
+        bivecmap.insert(1, 11);
+
+        assert_eq!(bivecmap.remove_pair(&1, &10), Some(()));
+        assert_eq!(bivecmap.remove_pair(&1, &10), None);
+
+        assert_eq!(bivecmap.get_left(&1).unwrap(), &HashMap::from([(11, ())]));
+        assert_eq!(bivecmap.get_right(&10), None);
+    }
+
+    #[test]
+    fn remove_left_returns_removed_counterparts() {
+        let mut bivecmap = BiSetMap::default();
+        bivecmap.insert(1, 10);
+
+        assert_eq!(bivecmap.remove_left(&1), Some(HashMap::from([(10, ())])));
+        assert_eq!(bivecmap.remove_left(&1), None);
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut bivecmap: BiSetMap<_, _, RandomState> = BiSetMap::with_capacity(4);
+        bivecmap.insert(1, 10);
+
+        assert_eq!(bivecmap.get_left(&1).unwrap(), &HashMap::from([(10, ())]));
+        assert!(bivecmap.capacity() >= 4);
+    }
+
+    #[test]
+    fn reserve_and_shrink_to_fit() {
+        let mut bivecmap = BiSetMap::default();
+        bivecmap.reserve(8);
+        assert!(bivecmap.capacity() >= 8);
+
+        bivecmap.insert(1, 10);
+        bivecmap.remove_left(&1);
+        bivecmap.shrink_to_fit();
+
+        assert_eq!(bivecmap.get_left(&1), None);
+    }
+
+    #[test]
+    fn from_iterator_and_extend() {
+        let mut bivecmap: BiSetMap<_, _, RandomState> =
+            [(1, 10), (1, 11)].into_iter().collect();
+        bivecmap.extend([(2, 20)]);
+
+        assert_eq!(bivecmap.get_left(&1).unwrap(), &HashMap::from([(10, ()), (11, ())]));
+        assert_eq!(bivecmap.get_left(&2).unwrap(), &HashMap::from([(20, ())]));
+    }
+
+    #[test]
+    fn into_iter_yields_flattened_associations() {
+        let mut bivecmap = BiSetMap::default();
+        bivecmap.insert(1, 10);
+        bivecmap.insert(1, 11);
+
+        let mut by_ref: Vec<_> = (&bivecmap).into_iter().collect();
+        by_ref.sort();
+        assert_eq!(by_ref, vec![(1, 10), (1, 11)]);
+
+        let mut owned: Vec<_> = bivecmap.into_iter().collect();
+        owned.sort();
+        assert_eq!(owned, vec![(1, 10), (1, 11)]);
+    }
 }
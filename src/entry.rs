@@ -0,0 +1,128 @@
+use std::{
+    hash::{BuildHasher, Hash},
+    ops::{Deref, DerefMut},
+};
+
+use crate::BiSetDataMap;
+
+pub struct Entry<'a, K, V, D, S> {
+    map: &'a mut BiSetDataMap<K, V, S, D>,
+    k: K,
+    v: V,
+}
+
+impl<'a, K, V, D, S> Entry<'a, K, V, D, S>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    pub(crate) fn new(map: &'a mut BiSetDataMap<K, V, S, D>, k: K, v: V) -> Self {
+        Entry { map, k, v }
+    }
+
+    pub fn get(&self) -> Option<&D> {
+        self.map.left.get(&self.k).and_then(|vs| vs.get(&self.v))
+    }
+
+    pub fn insert(self, d: D) -> Option<D> {
+        let previous = self.get().cloned();
+        self.map.insert(self.k, self.v, d);
+        previous
+    }
+
+    pub fn remove(self) -> Option<D> {
+        self.map.remove_pair(&self.k, &self.v)
+    }
+
+    pub fn and_modify<F: FnOnce(&mut D)>(self, f: F) -> Self {
+        if let Some(d) = self.map.left.get_mut(&self.k).and_then(|vs| vs.get_mut(&self.v)) {
+            f(d);
+            let updated = d.clone();
+            self.map
+                .right
+                .get_mut(&self.v)
+                .unwrap()
+                .insert(self.k.clone(), updated);
+        }
+        self
+    }
+
+    pub fn or_insert(self, default: D) -> ValueMut<'a, K, V, D, S> {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> D>(self, default: F) -> ValueMut<'a, K, V, D, S> {
+        let value = match self.get() {
+            Some(d) => d.clone(),
+            None => {
+                let d = default();
+                self.map.insert(self.k.clone(), self.v.clone(), d.clone());
+                d
+            }
+        };
+        ValueMut {
+            map: self.map,
+            k: self.k,
+            v: self.v,
+            value,
+        }
+    }
+}
+
+pub struct ValueMut<'a, K, V, D, S>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    map: &'a mut BiSetDataMap<K, V, S, D>,
+    k: K,
+    v: V,
+    value: D,
+}
+
+impl<K, V, D, S> Deref for ValueMut<'_, K, V, D, S>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        &self.value
+    }
+}
+
+impl<K, V, D, S> DerefMut for ValueMut<'_, K, V, D, S>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    fn deref_mut(&mut self) -> &mut D {
+        &mut self.value
+    }
+}
+
+impl<K, V, D, S> Drop for ValueMut<'_, K, V, D, S>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    fn drop(&mut self) {
+        if let Some(vs) = self.map.left.get_mut(&self.k) {
+            vs.insert(self.v.clone(), self.value.clone());
+        }
+        if let Some(ks) = self.map.right.get_mut(&self.v) {
+            ks.insert(self.k.clone(), self.value.clone());
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+#[derive(Debug, Clone)]
+pub struct OrderedMap<K, V, S = RandomState> {
+    index: HashMap<K, usize, S>,
+    entries: Vec<(K, V)>,
+}
+
+impl<K, V> Default for OrderedMap<K, V, RandomState> {
+    #[inline]
+    fn default() -> OrderedMap<K, V, RandomState> {
+        OrderedMap {
+            index: HashMap::with_hasher(RandomState::default()),
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<K, V, S> OrderedMap<K, V, S>
+where
+    S: BuildHasher + Default,
+{
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        OrderedMap {
+            index: HashMap::with_capacity_and_hasher(capacity, Default::default()),
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub(crate) fn with_hasher(hasher: S) -> Self {
+        OrderedMap {
+            index: HashMap::with_hasher(hasher),
+            entries: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        OrderedMap {
+            index: HashMap::with_capacity_and_hasher(capacity, hasher),
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+}
+
+impl<K, V, S> OrderedMap<K, V, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    pub fn get(&self, k: &K) -> Option<&V> {
+        self.index.get(k).map(|&i| &self.entries[i].1)
+    }
+
+    pub(crate) fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        let i = *self.index.get(k)?;
+        Some(&mut self.entries[i].1)
+    }
+
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.index.contains_key(k)
+    }
+
+    pub(crate) fn insert(&mut self, k: K, v: V) -> Option<V> {
+        if let Some(&i) = self.index.get(&k) {
+            Some(std::mem::replace(&mut self.entries[i].1, v))
+        } else {
+            self.index.insert(k.clone(), self.entries.len());
+            self.entries.push((k, v));
+            None
+        }
+    }
+
+    pub(crate) fn remove(&mut self, k: &K) -> Option<V> {
+        let i = self.index.remove(k)?;
+        let (_, v) = self.entries.remove(i);
+        for pos in self.index.values_mut() {
+            if *pos > i {
+                *pos -= 1;
+            }
+        }
+        Some(v)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(K, V)> {
+        self.entries.iter()
+    }
+
+    pub(crate) fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.entries.iter_mut().map(|(_, v)| v)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity().min(self.index.capacity())
+    }
+
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.index.reserve(additional);
+        self.entries.reserve(additional);
+    }
+
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.index.shrink_to_fit();
+        self.entries.shrink_to_fit();
+    }
+}
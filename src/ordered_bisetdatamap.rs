@@ -0,0 +1,249 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::ordered::OrderedMap;
+
+#[derive(Debug, Clone)]
+pub struct OrderedBiSetDataMap<K, V, S = RandomState, D = ()> {
+    left: OrderedMap<K, OrderedMap<V, D, S>, S>,
+    right: OrderedMap<V, OrderedMap<K, D, S>, S>,
+}
+
+impl<K, V, D> Default for OrderedBiSetDataMap<K, V, RandomState, D> {
+    #[inline]
+    fn default() -> OrderedBiSetDataMap<K, V, RandomState, D> {
+        OrderedBiSetDataMap {
+            left: OrderedMap::default(),
+            right: OrderedMap::default(),
+        }
+    }
+}
+
+impl<K, V, S, D> OrderedBiSetDataMap<K, V, S, D>
+where
+    S: BuildHasher + Default,
+{
+    pub fn with_capacity(capacity: usize) -> Self {
+        OrderedBiSetDataMap {
+            left: OrderedMap::with_capacity(capacity),
+            right: OrderedMap::with_capacity(capacity),
+        }
+    }
+
+    pub fn with_hasher(hasher: S) -> Self
+    where
+        S: Clone,
+    {
+        OrderedBiSetDataMap {
+            left: OrderedMap::with_hasher(hasher.clone()),
+            right: OrderedMap::with_hasher(hasher),
+        }
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self
+    where
+        S: Clone,
+    {
+        OrderedBiSetDataMap {
+            left: OrderedMap::with_capacity_and_hasher(capacity, hasher.clone()),
+            right: OrderedMap::with_capacity_and_hasher(capacity, hasher),
+        }
+    }
+}
+
+impl<K, V, S, D> OrderedBiSetDataMap<K, V, S, D>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    pub fn get_left(&self, k: &K) -> Option<&OrderedMap<V, D, S>> {
+        self.left.get(k)
+    }
+
+    pub fn get_right(&self, v: &V) -> Option<&OrderedMap<K, D, S>> {
+        self.right.get(v)
+    }
+
+    pub fn insert(&mut self, k: K, v: V, d: D) {
+        if self.left.get(&k).is_none() {
+            self.left.insert(k.clone(), OrderedMap::with_hasher(S::default()));
+        }
+        self.left.get_mut(&k).unwrap().insert(v.clone(), d.clone());
+
+        if self.right.get(&v).is_none() {
+            self.right.insert(v.clone(), OrderedMap::with_hasher(S::default()));
+        }
+        self.right.get_mut(&v).unwrap().insert(k, d);
+    }
+
+    pub fn collect(&self) -> Vec<(K, Vec<V>)> {
+        self.left
+            .iter()
+            .map(|(k, vs)| (k.clone(), vs.keys().cloned().collect()))
+            .collect()
+    }
+
+    pub fn rev_collect(&self) -> Vec<(V, Vec<K>)> {
+        self.right
+            .iter()
+            .map(|(v, ks)| (v.clone(), ks.keys().cloned().collect()))
+            .collect()
+    }
+
+    pub fn flat_collect(&self) -> Vec<(K, V, D)> {
+        self.left
+            .iter()
+            .flat_map(|(k, vs)| vs.iter().map(move |(v, d)| (k.clone(), v.clone(), d.clone())))
+            .collect()
+    }
+
+    pub fn rev_flat_collect(&self) -> Vec<(V, K, D)> {
+        self.right
+            .iter()
+            .flat_map(|(v, ks)| ks.iter().map(move |(k, d)| (v.clone(), k.clone(), d.clone())))
+            .collect()
+    }
+
+    pub fn contains(&self, k: &K, v: &V) -> bool {
+        self.left.get(k).is_some_and(|vs| vs.contains_key(v))
+    }
+
+    pub fn key_exists(&self, k: &K) -> bool {
+        self.left.contains_key(k)
+    }
+
+    pub fn value_exists(&self, v: &V) -> bool {
+        self.right.contains_key(v)
+    }
+
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.left.capacity().min(self.right.capacity())
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.left.reserve(additional);
+        self.right.reserve(additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.left.shrink_to_fit();
+        self.right.shrink_to_fit();
+        for vs in self.left.values_mut() {
+            vs.shrink_to_fit();
+        }
+        for ks in self.right.values_mut() {
+            ks.shrink_to_fit();
+        }
+    }
+
+    pub fn remove_left(&mut self, k: &K) -> Option<OrderedMap<V, D, S>> {
+        Self::remove(&mut self.left, &mut self.right, k)
+    }
+
+    pub fn remove_right(&mut self, v: &V) -> Option<OrderedMap<K, D, S>> {
+        Self::remove(&mut self.right, &mut self.left, v)
+    }
+
+    pub fn remove_pair(&mut self, k: &K, v: &V) -> Option<D> {
+        let d = self.left.get_mut(k).and_then(|vs| vs.remove(v))?;
+
+        if self.left.get(k).is_some_and(OrderedMap::is_empty) {
+            self.left.remove(k);
+        }
+
+        let ks = self.right.get_mut(v).unwrap();
+        ks.remove(k);
+        if ks.is_empty() {
+            self.right.remove(v);
+        }
+
+        Some(d)
+    }
+
+    fn remove<A: Eq + Hash + Clone, B: Eq + Hash + Clone>(
+        left_map: &mut OrderedMap<A, OrderedMap<B, D, S>, S>,
+        right_map: &mut OrderedMap<B, OrderedMap<A, D, S>, S>,
+        k: &A,
+    ) -> Option<OrderedMap<B, D, S>> {
+        let left = left_map.remove(k)?;
+        for right in left.keys() {
+            let elem = right_map.get_mut(right).unwrap();
+            elem.remove(k);
+            if elem.is_empty() {
+                right_map.remove(right);
+            }
+        }
+        Some(left)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_preserves_insertion_order() {
+        let mut bivecmap = OrderedBiSetDataMap::default();
+        bivecmap.insert(2, 20, "test1");
+        bivecmap.insert(1, 10, "test2");
+        bivecmap.insert(2, 21, "test3");
+
+        assert_eq!(
+            bivecmap.collect(),
+            vec![(2, vec![20, 21]), (1, vec![10])]
+        );
+        assert_eq!(
+            bivecmap.flat_collect(),
+            vec![(2, 20, "test1"), (2, 21, "test3"), (1, 10, "test2")]
+        );
+    }
+
+    #[test]
+    fn reinsert_updates_in_place_without_moving() {
+        let mut bivecmap = OrderedBiSetDataMap::default();
+        bivecmap.insert(1, 10, "test1");
+        bivecmap.insert(2, 20, "test2");
+        bivecmap.insert(1, 10, "updated");
+
+        assert_eq!(
+            bivecmap.collect(),
+            vec![(1, vec![10]), (2, vec![20])]
+        );
+        assert_eq!(bivecmap.get_left(&1).unwrap().get(&10), Some(&"updated"));
+    }
+
+    #[test]
+    fn remove_pair_unlinks_single_association() {
+        let mut bivecmap = OrderedBiSetDataMap::default();
+        bivecmap.insert(1, 10, "test1");
+        bivecmap.insert(1, 11, "test2");
+
+        assert_eq!(bivecmap.remove_pair(&1, &10), Some("test1"));
+        assert_eq!(bivecmap.remove_pair(&1, &10), None);
+        assert_eq!(bivecmap.get_left(&1).unwrap().keys().collect::<Vec<_>>(), vec![&11]);
+    }
+
+    #[test]
+    fn remove_left_preserves_order_of_remaining_keys() {
+        let mut bivecmap = OrderedBiSetDataMap::default();
+        bivecmap.insert(1, 10, "test1");
+        bivecmap.insert(2, 20, "test2");
+        bivecmap.insert(3, 30, "test3");
+
+        bivecmap.remove_left(&2);
+
+        assert_eq!(bivecmap.collect(), vec![(1, vec![10]), (3, vec![30])]);
+    }
+}
@@ -0,0 +1,269 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hash},
+    sync::{Arc, Mutex},
+};
+
+use crate::BiSetDataMap;
+
+#[derive(Debug)]
+pub struct SharedBiSetDataMap<K, V, S = RandomState, D = ()>(Arc<Mutex<BiSetDataMap<K, V, S, D>>>);
+
+impl<K, V, S, D> Clone for SharedBiSetDataMap<K, V, S, D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        SharedBiSetDataMap(Arc::clone(&self.0))
+    }
+}
+
+impl<K, V, D> Default for SharedBiSetDataMap<K, V, RandomState, D> {
+    #[inline]
+    fn default() -> SharedBiSetDataMap<K, V, RandomState, D> {
+        SharedBiSetDataMap(Arc::new(Mutex::new(BiSetDataMap::default())))
+    }
+}
+
+impl<K, V, S, D> SharedBiSetDataMap<K, V, S, D>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+    D: Clone,
+{
+    pub fn get_left(&self, k: &K) -> Option<Vec<V>> {
+        let map = self.0.lock().unwrap();
+        map.get_left(k).map(|vs| vs.keys().cloned().collect())
+    }
+
+    pub fn get_right(&self, v: &V) -> Option<Vec<K>> {
+        let map = self.0.lock().unwrap();
+        map.get_right(v).map(|ks| ks.keys().cloned().collect())
+    }
+
+    pub fn insert(&self, k: K, v: V, d: D) {
+        self.0.lock().unwrap().insert(k, v, d);
+    }
+
+    pub fn collect(&self) -> Vec<(K, Vec<V>)> {
+        self.0.lock().unwrap().collect()
+    }
+
+    pub fn rev_collect(&self) -> Vec<(V, Vec<K>)> {
+        self.0.lock().unwrap().rev_collect()
+    }
+
+    pub fn flat_collect(&self) -> Vec<(K, V, D)> {
+        self.0.lock().unwrap().flat_collect()
+    }
+
+    pub fn rev_flat_collect(&self) -> Vec<(V, K, D)> {
+        self.0.lock().unwrap().rev_flat_collect()
+    }
+
+    pub fn contains(&self, k: &K, v: &V) -> bool {
+        self.0.lock().unwrap().contains(k, v)
+    }
+
+    pub fn key_exists(&self, k: &K) -> bool {
+        self.0.lock().unwrap().key_exists(k)
+    }
+
+    pub fn value_exists(&self, v: &V) -> bool {
+        self.0.lock().unwrap().value_exists(v)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.lock().unwrap().is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.0.lock().unwrap().capacity()
+    }
+
+    pub fn reserve(&self, additional: usize) {
+        self.0.lock().unwrap().reserve(additional);
+    }
+
+    pub fn shrink_to_fit(&self) {
+        self.0.lock().unwrap().shrink_to_fit();
+    }
+
+    pub fn remove_left(&self, k: &K) -> Option<Vec<V>> {
+        let mut map = self.0.lock().unwrap();
+        map.remove_left(k).map(|vs| vs.into_keys().collect())
+    }
+
+    pub fn remove_right(&self, v: &V) -> Option<Vec<K>> {
+        let mut map = self.0.lock().unwrap();
+        map.remove_right(v).map(|ks| ks.into_keys().collect())
+    }
+
+    pub fn remove_pair(&self, k: &K, v: &V) -> Option<D> {
+        self.0.lock().unwrap().remove_pair(k, v)
+    }
+}
+
+#[derive(Debug)]
+pub struct SharedBiSetMap<K, V, S = RandomState>(SharedBiSetDataMap<K, V, S, ()>);
+
+impl<K, V, S> Clone for SharedBiSetMap<K, V, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        SharedBiSetMap(self.0.clone())
+    }
+}
+
+impl<K, V> Default for SharedBiSetMap<K, V, RandomState> {
+    #[inline]
+    fn default() -> SharedBiSetMap<K, V, RandomState> {
+        SharedBiSetMap(SharedBiSetDataMap::default())
+    }
+}
+
+impl<K, V, S> SharedBiSetMap<K, V, S>
+where
+    K: Eq + Hash + Clone,
+    V: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    pub fn get_left(&self, k: &K) -> Option<Vec<V>> {
+        self.0.get_left(k)
+    }
+
+    pub fn get_right(&self, v: &V) -> Option<Vec<K>> {
+        self.0.get_right(v)
+    }
+
+    pub fn insert(&self, k: K, v: V) {
+        self.0.insert(k, v, ())
+    }
+
+    pub fn collect(&self) -> Vec<(K, Vec<V>)> {
+        self.0.collect()
+    }
+
+    pub fn rev_collect(&self) -> Vec<(V, Vec<K>)> {
+        self.0.rev_collect()
+    }
+
+    pub fn flat_collect(&self) -> Vec<(K, V)> {
+        self.0
+            .flat_collect()
+            .into_iter()
+            .map(|(k, v, _)| (k, v))
+            .collect()
+    }
+
+    pub fn rev_flat_collect(&self) -> Vec<(V, K)> {
+        self.0
+            .rev_flat_collect()
+            .into_iter()
+            .map(|(v, k, _)| (v, k))
+            .collect()
+    }
+
+    pub fn contains(&self, k: &K, v: &V) -> bool {
+        self.0.contains(k, v)
+    }
+
+    pub fn key_exists(&self, k: &K) -> bool {
+        self.0.key_exists(k)
+    }
+
+    pub fn value_exists(&self, v: &V) -> bool {
+        self.0.value_exists(v)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    pub fn reserve(&self, additional: usize) {
+        self.0.reserve(additional)
+    }
+
+    pub fn shrink_to_fit(&self) {
+        self.0.shrink_to_fit()
+    }
+
+    pub fn remove_left(&self, k: &K) -> Option<Vec<V>> {
+        self.0.remove_left(k)
+    }
+
+    pub fn remove_right(&self, v: &V) -> Option<Vec<K>> {
+        self.0.remove_right(v)
+    }
+
+    pub fn remove_pair(&self, k: &K, v: &V) -> Option<()> {
+        self.0.remove_pair(k, v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_shares_state() {
+        let map = SharedBiSetMap::default();
+        let other = map.clone();
+
+        map.insert(1, 10);
+        other.insert(1, 11);
+
+        assert_eq!(map.get_left(&1).unwrap().len(), 2);
+        assert_eq!(other.get_right(&10).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn remove_through_shared_handle() {
+        let map = SharedBiSetMap::default();
+        map.insert(1, 10);
+
+        assert_eq!(map.remove_left(&1), Some(vec![10]));
+        assert_eq!(map.remove_left(&1), None);
+        assert_eq!(map.get_left(&1), None);
+        assert_eq!(map.get_right(&10), None);
+    }
+
+    #[test]
+    fn query_api_matches_inner_map() {
+        let map = SharedBiSetDataMap::default();
+        map.insert(1, 10, "test1");
+        map.insert(1, 11, "test2");
+
+        assert!(map.contains(&1, &10));
+        assert!(map.key_exists(&1));
+        assert!(map.value_exists(&10));
+        assert_eq!(map.len(), 1);
+        assert!(!map.is_empty());
+
+        let mut collected = map.collect();
+        collected.iter_mut().for_each(|(_, vs)| vs.sort());
+        assert_eq!(collected, vec![(1, vec![10, 11])]);
+
+        assert_eq!(map.remove_pair(&1, &10), Some("test1"));
+        assert_eq!(map.remove_pair(&1, &10), None);
+    }
+
+    #[test]
+    fn data_variant_round_trip() {
+        let map = SharedBiSetDataMap::default();
+        map.insert(1, 10, "test");
+
+        assert_eq!(map.get_left(&1).unwrap(), vec![10]);
+        assert_eq!(map.get_right(&10).unwrap(), vec![1]);
+    }
+}